@@ -0,0 +1,32 @@
+//! Typed `browserActions` steps, serialized to the JSON shape Scrappey expects.
+
+use serde::Serialize;
+
+/// A single browser automation step sent as part of a request's `browserActions` array.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type")]
+pub enum BrowserAction {
+    #[serde(rename = "wait_for_selector")]
+    WaitForSelector {
+        #[serde(rename = "cssSelector")]
+        css_selector: String,
+    },
+    #[serde(rename = "execute_js")]
+    ExecuteJs { code: String },
+    #[serde(rename = "scroll")]
+    Scroll {
+        #[serde(rename = "cssSelector")]
+        css_selector: String,
+    },
+    #[serde(rename = "click")]
+    Click {
+        #[serde(rename = "cssSelector")]
+        css_selector: String,
+    },
+    #[serde(rename = "type")]
+    Type {
+        #[serde(rename = "cssSelector")]
+        css_selector: String,
+        text: String,
+    },
+}
@@ -0,0 +1,177 @@
+//! Disk-backed response cache keyed by a hash of the canonicalized request.
+
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::types::ScrappeyResponse;
+
+/// On-disk cache for idempotent `request.get` responses.
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    cache_dir: PathBuf,
+    ttl_secs: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    fetched_at: u64,
+    response: ScrappeyResponse,
+}
+
+impl ResponseCache {
+    pub fn new(cache_dir: impl Into<PathBuf>, ttl_secs: u64) -> Self {
+        Self {
+            cache_dir: cache_dir.into(),
+            ttl_secs,
+        }
+    }
+
+    /// Hash `cmd` plus the sorted JSON payload, excluding the `session` field.
+    pub fn key(cmd: &str, payload: &Value) -> String {
+        let mut canonical = payload.as_object().cloned().unwrap_or_default();
+        canonical.remove("session");
+
+        let mut hasher = Sha256::new();
+        hasher.update(cmd.as_bytes());
+        hasher.update(serde_json::to_vec(&canonical).unwrap_or_default());
+        hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+
+    fn path_for(&self, key: &str) -> PathBuf {
+        self.cache_dir.join(format!("{key}.json"))
+    }
+
+    /// Return the cached response for `key` if present and not yet stale.
+    pub fn get(&self, key: &str) -> Option<ScrappeyResponse> {
+        let raw = std::fs::read(self.path_for(key)).ok()?;
+        let entry: CacheEntry = serde_json::from_slice(&raw).ok()?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+        if now.saturating_sub(entry.fetched_at) >= self.ttl_secs {
+            return None;
+        }
+        Some(entry.response)
+    }
+
+    /// Write `response` under `key`, replacing any existing entry atomically.
+    pub fn put(&self, key: &str, response: &ScrappeyResponse) -> std::io::Result<()> {
+        std::fs::create_dir_all(&self.cache_dir)?;
+        let fetched_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let serialized = serde_json::to_vec(&CacheEntry {
+            fetched_at,
+            response: response.clone(),
+        })?;
+
+        let tmp_path = self
+            .cache_dir
+            .join(format!("{key}.{}.tmp", std::process::id()));
+        std::fs::write(&tmp_path, serialized)?;
+        std::fs::rename(&tmp_path, self.path_for(key))?;
+        Ok(())
+    }
+
+    /// Explicitly evict a cached entry (the `bust()` escape hatch).
+    pub fn bust(&self, key: &str) -> std::io::Result<()> {
+        match std::fs::remove_file(self.path_for(key)) {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    /// A cache rooted in a unique temp directory, so tests can run concurrently
+    /// without touching each other's files. Callers are expected to `remove_dir_all`
+    /// the directory once done.
+    fn temp_cache(ttl_secs: u64) -> ResponseCache {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let dir =
+            std::env::temp_dir().join(format!("scrappey_cache_test_{}_{n}", std::process::id()));
+        ResponseCache::new(dir, ttl_secs)
+    }
+
+    #[test]
+    fn key_excludes_the_session_field() {
+        let with_session = serde_json::json!({ "url": "https://example.com", "session": "abc" });
+        let without_session = serde_json::json!({ "url": "https://example.com" });
+        assert_eq!(
+            ResponseCache::key("request.get", &with_session),
+            ResponseCache::key("request.get", &without_session)
+        );
+    }
+
+    #[test]
+    fn key_differs_by_cmd_and_by_payload() {
+        let payload = serde_json::json!({ "url": "https://example.com" });
+        assert_ne!(
+            ResponseCache::key("request.get", &payload),
+            ResponseCache::key("request.post", &payload)
+        );
+
+        let other_payload = serde_json::json!({ "url": "https://example.org" });
+        assert_ne!(
+            ResponseCache::key("request.get", &payload),
+            ResponseCache::key("request.get", &other_payload)
+        );
+    }
+
+    #[test]
+    fn put_then_get_round_trips_within_ttl() {
+        let cache = temp_cache(60);
+        let response = ScrappeyResponse {
+            solution: None,
+            time_elapsed: Some(42),
+            data: Some("success".to_string()),
+            session: None,
+            error: None,
+        };
+
+        cache.put("round-trip", &response).unwrap();
+        let cached = cache
+            .get("round-trip")
+            .expect("entry should still be fresh");
+        assert_eq!(cached.time_elapsed, Some(42));
+        assert_eq!(cached.data.as_deref(), Some("success"));
+
+        std::fs::remove_dir_all(&cache.cache_dir).ok();
+    }
+
+    #[test]
+    fn get_returns_none_once_past_the_ttl() {
+        let cache = temp_cache(0);
+        let response = ScrappeyResponse {
+            solution: None,
+            time_elapsed: None,
+            data: None,
+            session: None,
+            error: None,
+        };
+
+        cache.put("stale", &response).unwrap();
+        assert!(cache.get("stale").is_none());
+
+        std::fs::remove_dir_all(&cache.cache_dir).ok();
+    }
+
+    #[test]
+    fn bust_is_idempotent_for_a_missing_entry() {
+        let cache = temp_cache(60);
+        assert!(cache.bust("does-not-exist").is_ok());
+    }
+}
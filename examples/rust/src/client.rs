@@ -0,0 +1,373 @@
+//! Typed request builders for the Scrappey API.
+//!
+//! Every request made through `raw` is wrapped in a `tracing` span and emits
+//! `metrics` counters/histograms/gauges.
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use reqwest::Client;
+use serde_json::{json, Map, Value};
+
+use crate::browser_action::BrowserAction;
+use crate::cache::ResponseCache;
+use crate::error::{ScrappeyError, ScrappeyErrorCode};
+use crate::session::{Session, SessionOptions};
+use crate::types::ScrappeyResponse;
+
+/// Entry point for the Scrappey API. Holds the underlying HTTP client, base URL
+/// and API key, and hands out typed builders for each command.
+#[derive(Debug, Clone)]
+pub struct ScrappeyClient {
+    http: Client,
+    base_url: String,
+    api_key: String,
+    cache: Option<ResponseCache>,
+}
+
+impl ScrappeyClient {
+    /// Build a client with the default base URL and a default-configured `reqwest::Client`.
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self::with_http_client(api_key, Client::new())
+    }
+
+    /// Build a client reusing an existing `reqwest::Client` (e.g. one with a custom timeout).
+    pub fn with_http_client(api_key: impl Into<String>, http: Client) -> Self {
+        Self {
+            http,
+            base_url: "https://publisher.scrappey.com/api/v1".to_string(),
+            api_key: api_key.into(),
+            cache: None,
+        }
+    }
+
+    /// Enable the on-disk response cache for idempotent `request.get` calls.
+    pub fn with_cache(mut self, cache_dir: impl Into<std::path::PathBuf>, ttl_secs: u64) -> Self {
+        self.cache = Some(ResponseCache::new(cache_dir, ttl_secs));
+        self
+    }
+
+    /// Start building a `request.get` call.
+    pub fn get(&self, url: impl Into<String>) -> GetRequestBuilder<'_> {
+        GetRequestBuilder {
+            client: self,
+            url: url.into(),
+            proxy_country: None,
+            premium_proxy: None,
+            cloudflare_bypass: None,
+            session: None,
+            browser_actions: Vec::new(),
+            force_refresh: false,
+        }
+    }
+
+    /// Start building a `request.post` call.
+    pub fn post(&self, url: impl Into<String>) -> PostRequestBuilder<'_> {
+        PostRequestBuilder {
+            client: self,
+            url: url.into(),
+            post_data: None,
+            proxy_country: None,
+            premium_proxy: None,
+            session: None,
+        }
+    }
+
+    /// Create a session. Requests issued through the returned `Session` carry
+    /// the `session` field automatically, and the session is destroyed
+    /// server-side when it's dropped (or explicitly via `Session::close`).
+    pub async fn create_session(&self, opts: SessionOptions) -> Result<Session, ScrappeyError> {
+        let mut payload = Map::new();
+        if let Some(proxy_country) = &opts.proxy_country {
+            payload.insert("proxyCountry".to_string(), json!(proxy_country));
+        }
+        if let Some(premium_proxy) = opts.premium_proxy {
+            payload.insert("premiumProxy".to_string(), json!(premium_proxy));
+        }
+
+        let response = self.execute("sessions.create", payload).await?;
+        let session_id = response.session.ok_or_else(|| ScrappeyError::Api {
+            code: ScrappeyErrorCode::Unknown,
+            message: "sessions.create did not return a session id".to_string(),
+        })?;
+
+        Ok(Session::new(Arc::new(self.clone()), session_id))
+    }
+
+    /// Escape hatch for commands without a dedicated builder (e.g. `sessions.create`).
+    ///
+    /// Wrapped in a `scrappey_request` tracing span and emits the
+    /// `scrappey_requests_total` counter, `scrappey_request_duration_ms`
+    /// histogram, and `scrappey_requests_in_flight` gauge.
+    pub async fn raw(&self, cmd: &str, data: Value) -> Result<ScrappeyResponse, ScrappeyError> {
+        let mut payload = data.as_object().cloned().unwrap_or_default();
+        payload.insert("cmd".to_string(), json!(cmd));
+
+        let host = request_host(&payload);
+        let has_session = payload.contains_key("session");
+        let span = tracing::info_span!(
+            "scrappey_request",
+            cmd = %cmd,
+            host = %host,
+            session = has_session,
+            status = tracing::field::Empty,
+        );
+        let _enter = span.enter();
+
+        let _in_flight = InFlightGuard::new(metrics::gauge!("scrappey_requests_in_flight"));
+        let started = Instant::now();
+        let result = self.send_raw(&payload).await.and_then(|response| {
+            if let Some(status_code) = response.solution.as_ref().and_then(|s| s.status_code) {
+                span.record("status", status_code);
+            }
+            match response.error.clone() {
+                Some(message) => Err(ScrappeyError::Api {
+                    code: ScrappeyErrorCode::parse(&message),
+                    message,
+                }),
+                None => Ok(response),
+            }
+        });
+
+        let outcome = match &result {
+            Ok(_) => "success",
+            Err(ScrappeyError::TimedOut) => "timeout",
+            Err(_) => "error",
+        };
+        metrics::counter!(
+            "scrappey_requests_total",
+            "cmd" => cmd.to_string(),
+            "outcome" => outcome,
+        )
+        .increment(1);
+
+        let duration_ms = result
+            .as_ref()
+            .ok()
+            .and_then(|response| response.time_elapsed)
+            .map(|ms| ms as f64)
+            .unwrap_or_else(|| started.elapsed().as_secs_f64() * 1000.0);
+        metrics::histogram!("scrappey_request_duration_ms", "cmd" => cmd.to_string())
+            .record(duration_ms);
+
+        result
+    }
+
+    async fn send_raw(
+        &self,
+        payload: &Map<String, Value>,
+    ) -> Result<ScrappeyResponse, ScrappeyError> {
+        let response = self
+            .http
+            .post(format!("{}?key={}", self.base_url, self.api_key))
+            .header("Content-Type", "application/json")
+            .json(payload)
+            .send()
+            .await?;
+
+        // Treat 5xx as a retryable transport failure rather than trying (and
+        // likely failing) to decode a body that may not be a `ScrappeyResponse`.
+        if response.status().is_server_error() {
+            let err = response.error_for_status().unwrap_err();
+            return Err(ScrappeyError::from(err));
+        }
+
+        let result: ScrappeyResponse = response.json().await?;
+        Ok(result)
+    }
+
+    async fn execute(
+        &self,
+        cmd: &str,
+        payload: Map<String, Value>,
+    ) -> Result<ScrappeyResponse, ScrappeyError> {
+        self.raw(cmd, Value::Object(payload)).await
+    }
+}
+
+/// Fluent builder for a `request.get` call.
+pub struct GetRequestBuilder<'a> {
+    client: &'a ScrappeyClient,
+    url: String,
+    proxy_country: Option<String>,
+    premium_proxy: Option<bool>,
+    cloudflare_bypass: Option<bool>,
+    session: Option<String>,
+    browser_actions: Vec<BrowserAction>,
+    force_refresh: bool,
+}
+
+impl<'a> GetRequestBuilder<'a> {
+    pub fn proxy_country(mut self, country: impl Into<String>) -> Self {
+        self.proxy_country = Some(country.into());
+        self
+    }
+
+    pub fn premium_proxy(mut self, enabled: bool) -> Self {
+        self.premium_proxy = Some(enabled);
+        self
+    }
+
+    pub fn cloudflare_bypass(mut self, enabled: bool) -> Self {
+        self.cloudflare_bypass = Some(enabled);
+        self
+    }
+
+    pub fn session(mut self, session_id: impl Into<String>) -> Self {
+        self.session = Some(session_id.into());
+        self
+    }
+
+    pub fn browser_actions(mut self, actions: Vec<BrowserAction>) -> Self {
+        self.browser_actions = actions;
+        self
+    }
+
+    /// Skip the cache on this call, forcing a network fetch and refreshing the entry.
+    pub fn force_refresh(mut self, force_refresh: bool) -> Self {
+        self.force_refresh = force_refresh;
+        self
+    }
+
+    /// Evict the cached entry for this exact combination of GET options, if
+    /// any is cached. Requires `with_cache`.
+    pub fn bust_cache(self) -> Result<(), ScrappeyError> {
+        if let Some(cache) = &self.client.cache {
+            let key = ResponseCache::key("request.get", &Value::Object(self.build_payload()));
+            cache.bust(&key)?;
+        }
+        Ok(())
+    }
+
+    fn build_payload(&self) -> Map<String, Value> {
+        let mut payload = Map::new();
+        payload.insert("url".to_string(), json!(self.url));
+        if let Some(proxy_country) = &self.proxy_country {
+            payload.insert("proxyCountry".to_string(), json!(proxy_country));
+        }
+        if let Some(premium_proxy) = self.premium_proxy {
+            payload.insert("premiumProxy".to_string(), json!(premium_proxy));
+        }
+        if let Some(cloudflare_bypass) = self.cloudflare_bypass {
+            payload.insert("cloudflareBypass".to_string(), json!(cloudflare_bypass));
+        }
+        if let Some(session) = &self.session {
+            payload.insert("session".to_string(), json!(session));
+        }
+        if !self.browser_actions.is_empty() {
+            payload.insert("browserActions".to_string(), json!(self.browser_actions));
+        }
+        payload
+    }
+
+    pub async fn send(self) -> Result<ScrappeyResponse, ScrappeyError> {
+        let cacheable = self.session.is_none() && self.browser_actions.is_empty();
+        let payload = self.build_payload();
+
+        let cache_key = if cacheable {
+            self.client
+                .cache
+                .as_ref()
+                .map(|_| ResponseCache::key("request.get", &Value::Object(payload.clone())))
+        } else {
+            None
+        };
+
+        if let (Some(cache), Some(key)) = (&self.client.cache, &cache_key) {
+            if !self.force_refresh {
+                if let Some(cached) = cache.get(key) {
+                    return Ok(cached);
+                }
+            }
+        }
+
+        let response = self.client.execute("request.get", payload).await?;
+
+        if let (Some(cache), Some(key)) = (&self.client.cache, &cache_key) {
+            cache.put(key, &response)?;
+        }
+
+        Ok(response)
+    }
+}
+
+/// Fluent builder for a `request.post` call.
+pub struct PostRequestBuilder<'a> {
+    client: &'a ScrappeyClient,
+    url: String,
+    post_data: Option<String>,
+    proxy_country: Option<String>,
+    premium_proxy: Option<bool>,
+    session: Option<String>,
+}
+
+impl<'a> PostRequestBuilder<'a> {
+    pub fn post_data(mut self, data: impl Into<String>) -> Self {
+        self.post_data = Some(data.into());
+        self
+    }
+
+    pub fn proxy_country(mut self, country: impl Into<String>) -> Self {
+        self.proxy_country = Some(country.into());
+        self
+    }
+
+    pub fn premium_proxy(mut self, enabled: bool) -> Self {
+        self.premium_proxy = Some(enabled);
+        self
+    }
+
+    pub fn session(mut self, session_id: impl Into<String>) -> Self {
+        self.session = Some(session_id.into());
+        self
+    }
+
+    pub async fn send(self) -> Result<ScrappeyResponse, ScrappeyError> {
+        let mut payload = Map::new();
+        payload.insert("url".to_string(), json!(self.url));
+        if let Some(post_data) = self.post_data {
+            payload.insert("postData".to_string(), json!(post_data));
+        }
+        if let Some(proxy_country) = self.proxy_country {
+            payload.insert("proxyCountry".to_string(), json!(proxy_country));
+        }
+        if let Some(premium_proxy) = self.premium_proxy {
+            payload.insert("premiumProxy".to_string(), json!(premium_proxy));
+        }
+        if let Some(session) = self.session {
+            payload.insert("session".to_string(), json!(session));
+        }
+
+        self.client.execute("request.post", payload).await
+    }
+}
+
+/// Holds the `scrappey_requests_in_flight` gauge incremented for the
+/// lifetime of a `raw` call. Decrements on drop, so the gauge stays correct
+/// even if the call's future is cancelled (e.g. by `tokio::time::timeout`)
+/// instead of run to completion.
+struct InFlightGuard(metrics::Gauge);
+
+impl InFlightGuard {
+    fn new(gauge: metrics::Gauge) -> Self {
+        gauge.increment(1.0);
+        Self(gauge)
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.0.decrement(1.0);
+    }
+}
+
+/// Best-effort host extraction from a request payload's `url` field, for the
+/// tracing span attached to each `raw` call.
+fn request_host(payload: &Map<String, Value>) -> String {
+    payload
+        .get("url")
+        .and_then(Value::as_str)
+        .and_then(|url| reqwest::Url::parse(url).ok())
+        .and_then(|url| url.host_str().map(str::to_string))
+        .unwrap_or_else(|| "-".to_string())
+}
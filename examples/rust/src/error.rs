@@ -0,0 +1,152 @@
+//! Typed error type for the Scrappey API, distinguishing transport failures,
+//! timeouts, and API-level errors so callers can react to specific failures.
+
+use std::fmt;
+
+/// Errors that can occur when talking to the Scrappey API.
+#[derive(Debug)]
+pub enum ScrappeyError {
+    /// The underlying HTTP request failed for a reason other than a timeout.
+    Transport(reqwest::Error),
+    /// The request timed out before Scrappey responded.
+    TimedOut,
+    /// The response body could not be deserialized into the expected shape.
+    Deserialize(serde_json::Error),
+    /// Scrappey accepted the request but reported an error for it.
+    Api {
+        code: ScrappeyErrorCode,
+        message: String,
+    },
+    /// Reading or writing the on-disk response cache failed.
+    Cache(std::io::Error),
+}
+
+impl fmt::Display for ScrappeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScrappeyError::Transport(err) => write!(f, "transport error: {err}"),
+            ScrappeyError::TimedOut => write!(f, "request timed out"),
+            ScrappeyError::Deserialize(err) => write!(f, "failed to deserialize response: {err}"),
+            ScrappeyError::Api { code, message } => {
+                write!(f, "scrappey api error ({code:?}): {message}")
+            }
+            ScrappeyError::Cache(err) => write!(f, "cache error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ScrappeyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ScrappeyError::Transport(err) => Some(err),
+            ScrappeyError::Deserialize(err) => Some(err),
+            ScrappeyError::Cache(err) => Some(err),
+            ScrappeyError::TimedOut | ScrappeyError::Api { .. } => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for ScrappeyError {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            ScrappeyError::TimedOut
+        } else {
+            ScrappeyError::Transport(err)
+        }
+    }
+}
+
+impl From<serde_json::Error> for ScrappeyError {
+    fn from(err: serde_json::Error) -> Self {
+        ScrappeyError::Deserialize(err)
+    }
+}
+
+impl From<std::io::Error> for ScrappeyError {
+    fn from(err: std::io::Error) -> Self {
+        ScrappeyError::Cache(err)
+    }
+}
+
+/// Known Scrappey `error` strings, parsed into a stable code so callers don't
+/// have to match on free-form message text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrappeyErrorCode {
+    InvalidKey,
+    ProxyFailure,
+    CaptchaUnsolved,
+    NavigationTimeout,
+    Unknown,
+}
+
+impl ScrappeyErrorCode {
+    /// Parse a Scrappey `error` string into a stable code.
+    pub fn parse(message: &str) -> Self {
+        let normalized = message.to_lowercase();
+        if normalized.contains("invalid") && normalized.contains("key") {
+            ScrappeyErrorCode::InvalidKey
+        } else if normalized.contains("captcha") {
+            ScrappeyErrorCode::CaptchaUnsolved
+        } else if normalized.contains("navigation") && normalized.contains("timeout") {
+            ScrappeyErrorCode::NavigationTimeout
+        } else if normalized.contains("proxy") {
+            ScrappeyErrorCode::ProxyFailure
+        } else {
+            ScrappeyErrorCode::Unknown
+        }
+    }
+
+    /// Whether a request that failed with this code is worth retrying.
+    pub fn is_retryable(self) -> bool {
+        matches!(
+            self,
+            ScrappeyErrorCode::ProxyFailure | ScrappeyErrorCode::NavigationTimeout
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_recognizes_known_scrappey_error_strings() {
+        assert_eq!(
+            ScrappeyErrorCode::parse("Invalid API Key"),
+            ScrappeyErrorCode::InvalidKey
+        );
+        assert_eq!(
+            ScrappeyErrorCode::parse("captcha could not be solved"),
+            ScrappeyErrorCode::CaptchaUnsolved
+        );
+        assert_eq!(
+            ScrappeyErrorCode::parse("navigation timeout exceeded"),
+            ScrappeyErrorCode::NavigationTimeout
+        );
+        assert_eq!(
+            ScrappeyErrorCode::parse("proxy connection refused"),
+            ScrappeyErrorCode::ProxyFailure
+        );
+        assert_eq!(
+            ScrappeyErrorCode::parse("something else entirely"),
+            ScrappeyErrorCode::Unknown
+        );
+    }
+
+    #[test]
+    fn parse_is_case_insensitive() {
+        assert_eq!(
+            ScrappeyErrorCode::parse("INVALID KEY"),
+            ScrappeyErrorCode::InvalidKey
+        );
+    }
+
+    #[test]
+    fn only_proxy_and_navigation_timeout_codes_are_retryable() {
+        assert!(ScrappeyErrorCode::ProxyFailure.is_retryable());
+        assert!(ScrappeyErrorCode::NavigationTimeout.is_retryable());
+        assert!(!ScrappeyErrorCode::InvalidKey.is_retryable());
+        assert!(!ScrappeyErrorCode::CaptchaUnsolved.is_retryable());
+        assert!(!ScrappeyErrorCode::Unknown.is_retryable());
+    }
+}
@@ -3,173 +3,95 @@
 //! This example demonstrates how to use the Scrappey API from Rust
 //! for web scraping with Cloudflare bypass and browser automation.
 //!
-//! Prerequisites:
-//!   cargo add reqwest tokio serde serde_json
-//!
 //! Run:
 //!   cargo run
 //!
 //! Get your API key at: https://app.scrappey.com
 
-use reqwest::Client;
-use serde::{Deserialize, Serialize};
-use serde_json::{json, Value};
+// Most of `main` below is commented out: each `*_example` function is a
+// standalone, independently runnable illustration, not a suite meant to
+// execute in full, so several are never reachable from `main` by design.
+#![allow(dead_code)]
+
+mod browser_action;
+mod cache;
+mod client;
+mod error;
+mod queue;
+mod session;
+#[cfg(feature = "prometheus-metrics")]
+mod telemetry;
+mod types;
+
+use reqwest::Client as HttpClient;
+use serde_json::json;
 use std::env;
+use std::sync::Arc;
+
+use browser_action::BrowserAction;
+use client::ScrappeyClient;
+use error::ScrappeyError;
+use queue::{ScrapeQueue, ScrapeRequest};
+use session::SessionOptions;
 
 fn get_api_key() -> String {
     env::var("SCRAPPEY_API_KEY").unwrap_or_else(|_| "YOUR_API_KEY".to_string())
 }
 
-fn get_api_url() -> String {
-    "https://publisher.scrappey.com/api/v1".to_string()
-}
-
-// Response types
-#[derive(Debug, Deserialize)]
-struct Solution {
-    verified: Option<bool>,
-    response: Option<String>,
-    #[serde(rename = "statusCode")]
-    status_code: Option<i32>,
-    #[serde(rename = "currentUrl")]
-    current_url: Option<String>,
-    #[serde(rename = "userAgent")]
-    user_agent: Option<String>,
-    #[serde(rename = "cookieString")]
-    cookie_string: Option<String>,
-    screenshot: Option<String>,
-    #[serde(rename = "javascriptReturn")]
-    javascript_return: Option<Vec<Value>>,
-}
-
-#[derive(Debug, Deserialize)]
-struct ScrappeyResponse {
-    solution: Option<Solution>,
-    #[serde(rename = "timeElapsed")]
-    time_elapsed: Option<i32>,
-    data: Option<String>,
-    session: Option<String>,
-    error: Option<String>,
-}
-
-/// Send a request to the Scrappey API
-async fn scrappey_request(
-    client: &Client,
-    cmd: &str,
-    data: Value,
-) -> Result<ScrappeyResponse, Box<dyn std::error::Error>> {
-    let api_url = get_api_url();
-    let api_key = get_api_key();
-
-    let mut payload = data.as_object().cloned().unwrap_or_default();
-    payload.insert("cmd".to_string(), json!(cmd));
-
-    let response = client
-        .post(format!("{}?key={}", api_url, api_key))
-        .header("Content-Type", "application/json")
-        .json(&payload)
-        .send()
-        .await?;
-
-    let result: ScrappeyResponse = response.json().await?;
-    Ok(result)
-}
-
 /// Basic example: Simple GET request
-async fn basic_example(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
+async fn basic_example(client: &ScrappeyClient) -> Result<(), ScrappeyError> {
     println!("\n=== Basic Example ===\n");
 
-    let result = scrappey_request(
-        client,
-        "request.get",
-        json!({
-            "url": "https://httpbin.org/get"
-        }),
-    )
-    .await?;
-
-    if result.data.as_deref() == Some("success") {
-        if let Some(solution) = &result.solution {
-            println!("Status: {:?}", solution.status_code);
-            if let Some(response) = &solution.response {
-                let preview = if response.len() > 200 {
-                    format!("{}...", &response[..200])
-                } else {
-                    response.clone()
-                };
-                println!("Response: {}", preview);
-            }
+    let result = client.get("https://httpbin.org/get").send().await?;
+
+    if let Some(solution) = &result.solution {
+        println!("Status: {:?}", solution.status_code);
+        if let Some(response) = &solution.response {
+            let preview = if response.len() > 200 {
+                format!("{}...", &response[..200])
+            } else {
+                response.clone()
+            };
+            println!("Response: {}", preview);
         }
-    } else {
-        println!("Error: {:?}", result.error);
     }
 
     Ok(())
 }
 
 /// Session example: Maintain cookies across requests
-async fn session_example(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
+async fn session_example(client: &ScrappeyClient) -> Result<(), ScrappeyError> {
     println!("\n=== Session Example ===\n");
 
-    // Create session
-    let session_result = scrappey_request(
-        client,
-        "sessions.create",
-        json!({
-            "proxyCountry": "UnitedStates",
-            "premiumProxy": true
-        }),
-    )
-    .await?;
-
-    let session_id = session_result
-        .session
-        .clone()
-        .unwrap_or_default();
-    println!("Created session: {}", session_id);
-
-    // Use session for request
-    let result = scrappey_request(
-        client,
-        "request.get",
-        json!({
-            "url": "https://httpbin.org/get",
-            "session": &session_id
-        }),
-    )
-    .await?;
+    let session = client
+        .create_session(SessionOptions {
+            proxy_country: Some("UnitedStates".to_string()),
+            premium_proxy: Some(true),
+        })
+        .await?;
+    println!("Created session: {}", session.id());
 
+    let result = session.get("https://httpbin.org/get").send().await?;
     if let Some(solution) = &result.solution {
         println!("Request status: {:?}", solution.status_code);
     }
 
-    // Destroy session
-    scrappey_request(
-        client,
-        "sessions.destroy",
-        json!({
-            "session": &session_id
-        }),
-    )
-    .await?;
-    println!("Destroyed session: {}", session_id);
+    // Deterministic cleanup; dropping `session` would destroy it anyway.
+    session.close().await?;
+    println!("Destroyed session");
 
     Ok(())
 }
 
 /// POST request example
-async fn post_example(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
+async fn post_example(client: &ScrappeyClient) -> Result<(), ScrappeyError> {
     println!("\n=== POST Example ===\n");
 
-    let result = scrappey_request(
-        client,
-        "request.post",
-        json!({
-            "url": "https://httpbin.org/post",
-            "postData": "username=test&password=test123"
-        }),
-    )
-    .await?;
+    let result = client
+        .post("https://httpbin.org/post")
+        .post_data("username=test&password=test123")
+        .send()
+        .await?;
 
     if let Some(solution) = &result.solution {
         println!("POST status: {:?}", solution.status_code);
@@ -179,70 +101,114 @@ async fn post_example(client: &Client) -> Result<(), Box<dyn std::error::Error>>
 }
 
 /// Browser actions example
-async fn browser_actions_example(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
+async fn browser_actions_example(client: &ScrappeyClient) -> Result<(), ScrappeyError> {
     println!("\n=== Browser Actions Example ===\n");
 
-    let result = scrappey_request(
-        client,
-        "request.get",
-        json!({
-            "url": "https://example.com",
-            "browserActions": [
-                {"type": "wait_for_selector", "cssSelector": "body"},
-                {"type": "execute_js", "code": "document.title"},
-                {"type": "scroll", "cssSelector": "footer"}
-            ]
-        }),
-    )
-    .await?;
-
-    if result.data.as_deref() == Some("success") {
-        if let Some(solution) = &result.solution {
-            println!("Page loaded, status: {:?}", solution.status_code);
-            if let Some(js_return) = &solution.javascript_return {
-                println!("JS Return: {:?}", js_return);
-            }
+    let result = client
+        .get("https://example.com")
+        .browser_actions(vec![
+            BrowserAction::WaitForSelector {
+                css_selector: "body".to_string(),
+            },
+            BrowserAction::ExecuteJs {
+                code: "document.title".to_string(),
+            },
+            BrowserAction::Scroll {
+                css_selector: "footer".to_string(),
+            },
+        ])
+        .send()
+        .await?;
+
+    if let Some(solution) = &result.solution {
+        println!("Page loaded, status: {:?}", solution.status_code);
+        if let Some(js_return) = &solution.javascript_return {
+            println!("JS Return: {:?}", js_return);
         }
-    } else {
-        println!("Error: {:?}", result.error);
     }
 
     Ok(())
 }
 
 /// Cloudflare bypass example
-async fn cloudflare_example(client: &Client) -> Result<(), Box<dyn std::error::Error>> {
+async fn cloudflare_example(client: &ScrappeyClient) -> Result<(), ScrappeyError> {
     println!("\n=== Cloudflare Bypass Example ===\n");
 
-    let result = scrappey_request(
-        client,
-        "request.get",
-        json!({
-            "url": "https://example-protected-site.com",
-            "cloudflareBypass": true,
-            "premiumProxy": true,
-            "proxyCountry": "UnitedStates"
-        }),
-    )
-    .await?;
-
-    if result.data.as_deref() == Some("success") {
-        println!("Successfully bypassed!");
-    } else {
-        println!("Error: {:?}", result.error);
+    client
+        .get("https://example-protected-site.com")
+        .cloudflare_bypass(true)
+        .premium_proxy(true)
+        .proxy_country("UnitedStates")
+        .send()
+        .await?;
+
+    println!("Successfully bypassed!");
+
+    Ok(())
+}
+
+/// Cached GET example: repeated calls within the TTL are served from disk
+async fn cached_example(client: &ScrappeyClient) -> Result<(), ScrappeyError> {
+    println!("\n=== Cached Example ===\n");
+
+    let result = client.get("https://httpbin.org/get").send().await?;
+    println!(
+        "First call status: {:?}",
+        result.solution.and_then(|s| s.status_code)
+    );
+
+    // Served from `<cache_dir>/<hash>.json` instead of hitting the API again.
+    let cached = client.get("https://httpbin.org/get").send().await?;
+    println!(
+        "Second call status: {:?}",
+        cached.solution.and_then(|s| s.status_code)
+    );
+
+    client.get("https://httpbin.org/get").bust_cache()?;
+
+    Ok(())
+}
+
+/// Batch scraping example: scrape many URLs concurrently with retry/backoff
+async fn batch_scrape_example(client: Arc<ScrappeyClient>) -> Result<(), ScrappeyError> {
+    println!("\n=== Batch Scrape Example ===\n");
+
+    let urls = [
+        "https://httpbin.org/get",
+        "https://httpbin.org/status/500",
+        "https://httpbin.org/delay/1",
+    ];
+    let requests = urls
+        .iter()
+        .map(|url| ScrapeRequest::new("request.get", json!({ "url": url })))
+        .collect();
+
+    let queue = ScrapeQueue::new(client, 2);
+    for (url, result) in urls.iter().zip(queue.run(requests).await) {
+        match result {
+            Ok(response) => println!("{url}: data={:?}", response.data),
+            Err(err) => println!("{url}: failed after retries: {err}"),
+        }
     }
 
     Ok(())
 }
 
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
+async fn main() -> Result<(), ScrappeyError> {
     println!("Scrappey Rust Examples");
     println!("{}", "=".repeat(50));
 
-    let client = Client::builder()
+    // With the `prometheus-metrics` feature enabled, expose the counters and
+    // histograms `ScrappeyClient::raw` emits on http://0.0.0.0:9000/metrics:
+    // #[cfg(feature = "prometheus-metrics")]
+    // telemetry::install_prometheus_exporter("0.0.0.0:9000".parse().unwrap())?;
+
+    let http = HttpClient::builder()
         .timeout(std::time::Duration::from_secs(300))
         .build()?;
+    let client =
+        ScrappeyClient::with_http_client(get_api_key(), http).with_cache(".scrappey_cache", 300);
 
     basic_example(&client).await?;
     session_example(&client).await?;
@@ -250,6 +216,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Uncomment to run additional examples:
     // browser_actions_example(&client).await?;
     // cloudflare_example(&client).await?;
+    // cached_example(&client).await?;
+    // batch_scrape_example(Arc::new(client.clone())).await?;
 
     println!("\nâœ“ All examples completed!\n");
 
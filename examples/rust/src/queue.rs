@@ -0,0 +1,208 @@
+//! Bounded-concurrency batch scraping with automatic retry and backoff.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use rand::Rng;
+use serde_json::Value;
+use tokio::sync::Semaphore;
+use tokio::time::sleep;
+
+use crate::client::ScrappeyClient;
+use crate::error::ScrappeyError;
+use crate::types::ScrappeyResponse;
+
+/// One request to run through a `ScrapeQueue`: a command name plus its JSON payload.
+#[derive(Debug, Clone)]
+pub struct ScrapeRequest {
+    pub cmd: String,
+    pub payload: Value,
+}
+
+impl ScrapeRequest {
+    pub fn new(cmd: impl Into<String>, payload: Value) -> Self {
+        Self {
+            cmd: cmd.into(),
+            payload,
+        }
+    }
+}
+
+/// Retry policy applied to each queued request.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub retryable_errors: Vec<String>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            retryable_errors: vec![
+                "proxy_error".to_string(),
+                "timeout".to_string(),
+                "navigation_timeout".to_string(),
+            ],
+        }
+    }
+}
+
+/// Drives a batch of requests under a bounded concurrency limit, retrying
+/// transient failures with exponential backoff and jitter. Results are
+/// returned in the same order the requests were submitted, regardless of
+/// completion order.
+pub struct ScrapeQueue {
+    client: Arc<ScrappeyClient>,
+    concurrency: usize,
+    retry: RetryPolicy,
+}
+
+impl ScrapeQueue {
+    pub fn new(client: Arc<ScrappeyClient>, concurrency: usize) -> Self {
+        Self {
+            client,
+            concurrency,
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    pub async fn run(
+        &self,
+        requests: Vec<ScrapeRequest>,
+    ) -> Vec<Result<ScrappeyResponse, ScrappeyError>> {
+        let semaphore = Arc::new(Semaphore::new(self.concurrency));
+        let mut handles = Vec::with_capacity(requests.len());
+
+        for request in requests {
+            let client = Arc::clone(&self.client);
+            let semaphore = Arc::clone(&semaphore);
+            let retry = self.retry.clone();
+            handles.push(tokio::spawn(async move {
+                let _permit = semaphore
+                    .acquire_owned()
+                    .await
+                    .expect("scrape queue semaphore closed");
+                run_with_retry(&client, request, &retry).await
+            }));
+        }
+
+        let mut results = Vec::with_capacity(handles.len());
+        for handle in handles {
+            results.push(handle.await.expect("scrape task panicked"));
+        }
+        results
+    }
+}
+
+async fn run_with_retry(
+    client: &ScrappeyClient,
+    request: ScrapeRequest,
+    retry: &RetryPolicy,
+) -> Result<ScrappeyResponse, ScrappeyError> {
+    let mut attempt = 0;
+    loop {
+        match client.raw(&request.cmd, request.payload.clone()).await {
+            Ok(response) => return Ok(response),
+            Err(err) => {
+                if is_retryable(&err, retry) && attempt + 1 < retry.max_attempts {
+                    attempt += 1;
+                    sleep(backoff_delay(retry.base_delay, attempt)).await;
+                    continue;
+                }
+                return Err(err);
+            }
+        }
+    }
+}
+
+fn is_retryable(err: &ScrappeyError, retry: &RetryPolicy) -> bool {
+    match err {
+        ScrappeyError::TimedOut => true,
+        ScrappeyError::Transport(reqwest_err) => {
+            reqwest_err
+                .status()
+                .map(|status| status.is_server_error())
+                .unwrap_or(false)
+                || reqwest_err.is_connect()
+        }
+        ScrappeyError::Api { code, message } => {
+            retry
+                .retryable_errors
+                .iter()
+                .any(|pattern| message.contains(pattern.as_str()))
+                || code.is_retryable()
+        }
+        ScrappeyError::Deserialize(_) | ScrappeyError::Cache(_) => false,
+    }
+}
+
+fn backoff_delay(base_delay: Duration, attempt: u32) -> Duration {
+    let exponential = base_delay.saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)));
+    let jitter_ms = rand::thread_rng().gen_range(0..base_delay.as_millis().max(1) as u64);
+    exponential + Duration::from_millis(jitter_ms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ScrappeyErrorCode;
+
+    #[test]
+    fn backoff_delay_grows_exponentially_with_jitter_bounded_by_base_delay() {
+        let base_delay = Duration::from_millis(100);
+        for attempt in 1..=4 {
+            let delay = backoff_delay(base_delay, attempt);
+            let exponential = base_delay * 2u32.pow(attempt - 1);
+            assert!(
+                delay >= exponential,
+                "attempt {attempt}: {delay:?} < {exponential:?}"
+            );
+            assert!(
+                delay < exponential + base_delay,
+                "attempt {attempt}: {delay:?} >= {:?}",
+                exponential + base_delay
+            );
+        }
+    }
+
+    #[test]
+    fn is_retryable_treats_timeouts_as_retryable_and_transport_errors_by_status() {
+        let retry = RetryPolicy::default();
+        assert!(is_retryable(&ScrappeyError::TimedOut, &retry));
+        assert!(!is_retryable(
+            &ScrappeyError::Deserialize(serde_json::from_str::<Value>("not json").unwrap_err()),
+            &retry
+        ));
+    }
+
+    #[test]
+    fn is_retryable_matches_api_errors_by_configured_substring_or_code() {
+        let retry = RetryPolicy::default();
+
+        let matched_by_substring = ScrappeyError::Api {
+            code: ScrappeyErrorCode::Unknown,
+            message: "proxy_error: upstream refused the connection".to_string(),
+        };
+        assert!(is_retryable(&matched_by_substring, &retry));
+
+        let matched_by_code = ScrappeyError::Api {
+            code: ScrappeyErrorCode::NavigationTimeout,
+            message: "something unrelated to the configured patterns".to_string(),
+        };
+        assert!(is_retryable(&matched_by_code, &retry));
+
+        let not_retryable = ScrappeyError::Api {
+            code: ScrappeyErrorCode::InvalidKey,
+            message: "invalid api key".to_string(),
+        };
+        assert!(!is_retryable(&not_retryable, &retry));
+    }
+}
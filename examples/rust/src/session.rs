@@ -0,0 +1,110 @@
+//! RAII session handle: auto-destroys server-side when dropped.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde_json::json;
+
+use crate::client::{GetRequestBuilder, PostRequestBuilder, ScrappeyClient};
+use crate::error::ScrappeyError;
+
+/// Options used to create a new session via `ScrappeyClient::create_session`.
+#[derive(Debug, Clone, Default)]
+pub struct SessionOptions {
+    pub proxy_country: Option<String>,
+    pub premium_proxy: Option<bool>,
+}
+
+/// A Scrappey session. Requests issued through it automatically carry the
+/// `session` field, so cookies and browser state persist across calls.
+///
+/// Dropping a `Session` schedules `sessions.destroy` on the current Tokio
+/// runtime so a forgotten session doesn't linger server-side; call
+/// [`Session::close`] instead when you want to await that cleanup and
+/// observe errors.
+pub struct Session {
+    client: Arc<ScrappeyClient>,
+    session_id: String,
+    closed: bool,
+}
+
+impl Session {
+    pub(crate) fn new(client: Arc<ScrappeyClient>, session_id: String) -> Self {
+        Self {
+            client,
+            session_id,
+            closed: false,
+        }
+    }
+
+    /// The session id assigned by Scrappey.
+    pub fn id(&self) -> &str {
+        &self.session_id
+    }
+
+    /// Start building a `request.get` call scoped to this session.
+    pub fn get(&self, url: impl Into<String>) -> GetRequestBuilder<'_> {
+        self.client.get(url).session(&self.session_id)
+    }
+
+    /// Start building a `request.post` call scoped to this session.
+    pub fn post(&self, url: impl Into<String>) -> PostRequestBuilder<'_> {
+        self.client.post(url).session(&self.session_id)
+    }
+
+    /// Spawn a background task that pings this session every `interval` so
+    /// Scrappey doesn't expire it server-side while it sits idle. Drop or
+    /// abort the returned handle to stop pinging.
+    pub fn keep_alive(&self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let client = Arc::clone(&self.client);
+        let session_id = self.session_id.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                let _ = client
+                    .raw("sessions.ping", json!({ "session": session_id }))
+                    .await;
+            }
+        })
+    }
+
+    /// Destroy the session and await the result, instead of relying on `Drop`.
+    pub async fn close(mut self) -> Result<(), ScrappeyError> {
+        self.destroy().await?;
+        self.closed = true;
+        Ok(())
+    }
+
+    async fn destroy(&self) -> Result<(), ScrappeyError> {
+        self.client
+            .raw("sessions.destroy", json!({ "session": self.session_id }))
+            .await?;
+        Ok(())
+    }
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        if self.closed {
+            return;
+        }
+
+        let client = Arc::clone(&self.client);
+        let session_id = self.session_id.clone();
+
+        // `Drop` can't be async, so hand the actual `sessions.destroy` call
+        // off to the runtime. `Handle::spawn` panics if called while the
+        // runtime is already shutting down, so guard against that rather
+        // than letting it take down whatever is dropping us.
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                handle.spawn(async move {
+                    let _ = client
+                        .raw("sessions.destroy", json!({ "session": session_id }))
+                        .await;
+                });
+            }));
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//! Optional Prometheus wiring for the metrics `ScrappeyClient::raw` emits.
+//!
+//! Gated behind the `prometheus-metrics` Cargo feature (cargo run --features
+//! prometheus-metrics).
+
+use std::net::SocketAddr;
+
+use metrics_exporter_prometheus::{BuildError, PrometheusBuilder};
+
+/// Install a Prometheus recorder and serve `/metrics` on `addr`.
+///
+/// Call this once at startup, before issuing any requests through
+/// `ScrappeyClient`, so `scrappey_requests_total`,
+/// `scrappey_request_duration_ms`, and `scrappey_requests_in_flight` are
+/// captured by the recorder rather than dropped on the floor.
+pub fn install_prometheus_exporter(addr: SocketAddr) -> Result<(), BuildError> {
+    PrometheusBuilder::new().with_http_listener(addr).install()
+}
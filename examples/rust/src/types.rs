@@ -0,0 +1,31 @@
+//! Response types shared by every Scrappey command.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Solution {
+    pub verified: Option<bool>,
+    pub response: Option<String>,
+    #[serde(rename = "statusCode")]
+    pub status_code: Option<i32>,
+    #[serde(rename = "currentUrl")]
+    pub current_url: Option<String>,
+    #[serde(rename = "userAgent")]
+    pub user_agent: Option<String>,
+    #[serde(rename = "cookieString")]
+    pub cookie_string: Option<String>,
+    pub screenshot: Option<String>,
+    #[serde(rename = "javascriptReturn")]
+    pub javascript_return: Option<Vec<Value>>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ScrappeyResponse {
+    pub solution: Option<Solution>,
+    #[serde(rename = "timeElapsed")]
+    pub time_elapsed: Option<i32>,
+    pub data: Option<String>,
+    pub session: Option<String>,
+    pub error: Option<String>,
+}